@@ -0,0 +1,112 @@
+// src/anim.rs
+
+//! Animated GIF playback.
+//!
+//! A GIF is decoded once into an [`Animation`] (a list of frames with
+//! their per-frame delays), which is then inspected (frame count,
+//! dimensions) and played back in the terminal by clearing the screen
+//! between frames and sleeping for each frame's own delay.
+
+use crate::{render_ansi, render_to_string, Config};
+use image::codecs::gif::GifDecoder;
+use image::{AnimationDecoder, DynamicImage, GenericImageView};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// ANSI escape sequence that clears the screen and moves the cursor home.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// A single decoded GIF frame paired with its intended display duration.
+struct AnimFrame {
+    image: DynamicImage,
+    delay: Duration,
+}
+
+/// A fully decoded GIF, ready to be inspected or played without touching
+/// the file again.
+pub struct Animation {
+    frames: Vec<AnimFrame>,
+}
+
+impl Animation {
+    /// Decode every frame of the GIF at `path` along with its per-frame delay.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let reader = BufReader::new(File::open(path)?);
+        let decoder = GifDecoder::new(reader)?;
+
+        let frames = decoder
+            .into_frames()
+            .map(|frame| {
+                let frame = frame?;
+                let (numer, _denom) = frame.delay().numer_denom_ms();
+                let delay = Duration::from_millis(numer as u64);
+                let image = DynamicImage::ImageRgba8(frame.into_buffer());
+                Ok(AnimFrame { image, delay })
+            })
+            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+        Ok(Self { frames })
+    }
+
+    /// Whether this GIF has more than one frame.
+    ///
+    /// Single-frame GIFs are rendered through the normal still-image path;
+    /// only genuinely animated GIFs trigger playback mode.
+    pub fn is_animated(&self) -> bool {
+        self.frames.len() > 1
+    }
+
+    /// Dimensions of the first frame, e.g. for sizing output to fit the
+    /// terminal before playback starts.
+    pub fn dimensions(&self) -> (u32, u32) {
+        self.frames[0].image.dimensions()
+    }
+
+    /// The first frame as a still image, for single-frame GIFs that don't
+    /// go through playback mode.
+    pub fn into_first_frame(mut self) -> DynamicImage {
+        self.frames.swap_remove(0).image
+    }
+
+    /// Play this animation as ASCII art in the terminal.
+    ///
+    /// `fps_override`, when set to a positive rate, replaces each frame's
+    /// own delay with a fixed `1.0 / fps` duration; a zero or negative rate
+    /// keeps the frames' own delays. When `loop_forever` is true, playback
+    /// repeats until the process is interrupted; otherwise it plays
+    /// through once.
+    pub fn play(&self, config: &Config, color: bool, loop_forever: bool, fps_override: Option<f32>) {
+        loop {
+            for frame in &self.frames {
+                print!("{}", CLEAR_SCREEN);
+                let art = if color {
+                    render_ansi(&frame.image, config)
+                } else {
+                    render_to_string(&frame.image, config)
+                };
+                print!("{}", art);
+
+                let delay = match fps_override {
+                    Some(fps) if fps > 0.0 => Duration::from_secs_f32(1.0 / fps),
+                    _ => frame.delay,
+                };
+                sleep(delay);
+            }
+
+            if !loop_forever {
+                break;
+            }
+        }
+    }
+}
+
+/// Whether `path` looks like a GIF, based on its file extension.
+pub fn is_gif(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("gif"))
+        .unwrap_or(false)
+}
@@ -0,0 +1,70 @@
+// src/charset.rs
+
+//! Named built-in character ramps.
+//!
+//! [`resolve`] expands a friendly charset name (`block`, `detailed`, ...)
+//! into the actual ramp string used by the renderer. Anything that isn't
+//! a recognized name is passed through unchanged, so a raw custom ramp
+//! still works exactly as before.
+
+const DEFAULT: &str = " .:-=+*#%@";
+const BLOCK: &str = " ░▒▓█";
+const BRAILLE: &str = " ⠁⠃⠇⠏⠟⠿⣿";
+const DETAILED: &str = " .'`^\",:;Il!i><~+_-?][}{1)(|\\/tfjrxnuvczXYUJCLQ0OZmwqpdbkhao*#MW&8%B@$";
+const RUSSIAN: &str = " .,:;irsXA253hMHGS#9B&@";
+
+/// Resolve a charset name or raw ramp string into the effective ramp, in
+/// darkest-to-lightest order, optionally reversed by `invert` so the art
+/// reads correctly on light terminal backgrounds.
+///
+/// Returns an error if `spec` doesn't resolve to a named ramp and isn't
+/// itself a non-empty raw ramp, since an empty ramp has no character to map
+/// brightness onto.
+pub fn resolve(spec: &str, invert: bool) -> Result<String, Box<dyn std::error::Error>> {
+    let ramp = match spec.to_lowercase().as_str() {
+        "default" => DEFAULT,
+        "block" | "blocks" => BLOCK,
+        "braille" => BRAILLE,
+        "detailed" => DETAILED,
+        "russian" => RUSSIAN,
+        _ => spec,
+    };
+
+    if ramp.is_empty() {
+        return Err("charset ramp is empty: provide a named charset or a non-empty raw ramp".into());
+    }
+
+    Ok(if invert {
+        ramp.chars().rev().collect()
+    } else {
+        ramp.to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_named_ramps_case_insensitively() {
+        assert_eq!(resolve("Block", false).unwrap(), BLOCK);
+        assert_eq!(resolve("BLOCKS", false).unwrap(), BLOCK);
+        assert_eq!(resolve("russian", false).unwrap(), RUSSIAN);
+    }
+
+    #[test]
+    fn passes_through_unrecognized_specs_as_a_raw_ramp() {
+        assert_eq!(resolve(" .-+=%#", false).unwrap(), " .-+=%#");
+    }
+
+    #[test]
+    fn invert_reverses_the_resolved_ramp() {
+        assert_eq!(resolve("default", true).unwrap(), DEFAULT.chars().rev().collect::<String>());
+        assert_eq!(resolve(" .-+=%#", true).unwrap(), "#%=+-. ");
+    }
+
+    #[test]
+    fn empty_ramp_is_rejected_instead_of_panicking_downstream() {
+        assert!(resolve("", false).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+// src/edges.rs
+
+//! Edge-aware structural ASCII mode (`--edges`).
+//!
+//! Instead of mapping brightness straight to a character ramp, this mode
+//! runs a 3x3 Sobel operator over the sampled brightness grid and picks a
+//! direction-aligned glyph (`|`, `-`, `/`, `\`) wherever the gradient
+//! magnitude is strong enough to indicate an edge, producing line-art that
+//! follows the image's structure rather than flat shading.
+
+/// Gradient magnitude above which a cell is considered part of an edge.
+/// Cells below this fall back to the normal brightness-to-charset mapping.
+pub const EDGE_THRESHOLD: f32 = 60.0;
+
+const SOBEL_X: [[f32; 3]; 3] = [[-1.0, 0.0, 1.0], [-2.0, 0.0, 2.0], [-1.0, 0.0, 1.0]];
+const SOBEL_Y: [[f32; 3]; 3] = [[-1.0, -2.0, -1.0], [0.0, 0.0, 0.0], [1.0, 2.0, 1.0]];
+
+/// Sample the brightness grid at `(x, y)`, clamping out-of-bounds
+/// coordinates to the nearest edge pixel so the Sobel kernel is well
+/// defined at the border of the image.
+fn sample(grid: &[Vec<u8>], x: isize, y: isize) -> f32 {
+    let height = grid.len() as isize;
+    let width = grid[0].len() as isize;
+    let cx = x.clamp(0, width - 1) as usize;
+    let cy = y.clamp(0, height - 1) as usize;
+    grid[cy][cx] as f32
+}
+
+/// Classify the cell at `(x, y)` in a brightness `grid` as an edge glyph,
+/// or `None` if the gradient magnitude there is below [`EDGE_THRESHOLD`]
+/// and the caller should fall back to the normal brightness mapping.
+pub fn classify(grid: &[Vec<u8>], x: usize, y: usize) -> Option<char> {
+    let (x, y) = (x as isize, y as isize);
+
+    let mut gx = 0.0;
+    let mut gy = 0.0;
+    for (ky, row) in SOBEL_X.iter().enumerate() {
+        for (kx, &weight) in row.iter().enumerate() {
+            let value = sample(grid, x + kx as isize - 1, y + ky as isize - 1);
+            gx += weight * value;
+            gy += SOBEL_Y[ky][kx] * value;
+        }
+    }
+
+    let magnitude = (gx * gx + gy * gy).sqrt();
+    if magnitude <= EDGE_THRESHOLD {
+        return None;
+    }
+
+    // `theta` below is the gradient direction, which points across the
+    // edge (its normal), not along it. A vertical edge (brightness
+    // changing left-to-right) has a gradient near 0 degrees, so the bins
+    // assign the glyph that runs *along* the edge by picking the one
+    // perpendicular to theta: near-0 gradients get '|', near-90 gradients
+    // get '-', and the diagonal bins swap '/' and '\' to match.
+    let theta = gy.atan2(gx).to_degrees();
+    let theta = if theta > 90.0 {
+        theta - 180.0
+    } else if theta <= -90.0 {
+        theta + 180.0
+    } else {
+        theta
+    };
+
+    let glyph = match theta {
+        t if t.abs() <= 22.5 => '|',
+        t if (22.5..67.5).contains(&t) => '/',
+        t if (-67.5..-22.5).contains(&t) => '\\',
+        _ => '-',
+    };
+
+    Some(glyph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 7x7 brightness grid split into a dark half and a bright half
+    /// along a vertical line (`vertical_edge`) or a horizontal one, for
+    /// exercising a single strong edge.
+    fn split_grid(vertical_edge: bool) -> Vec<Vec<u8>> {
+        (0..7)
+            .map(|y| {
+                (0..7)
+                    .map(|x| {
+                        let past_edge = if vertical_edge { x >= 4 } else { y >= 4 };
+                        if past_edge {
+                            255
+                        } else {
+                            0
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn vertical_edge_renders_as_pipe() {
+        let grid = split_grid(true);
+        assert_eq!(classify(&grid, 3, 3), Some('|'));
+    }
+
+    #[test]
+    fn horizontal_edge_renders_as_dash() {
+        let grid = split_grid(false);
+        assert_eq!(classify(&grid, 3, 3), Some('-'));
+    }
+
+    #[test]
+    fn flat_region_has_no_edge_glyph() {
+        let grid = vec![vec![128u8; 7]; 7];
+        assert_eq!(classify(&grid, 3, 3), None);
+    }
+}
@@ -0,0 +1,51 @@
+// src/export.rs
+
+//! Output targets: writing rendered art to the terminal, a plain text
+//! file, or a colored HTML fragment.
+
+use crate::Glyph;
+
+/// Where rendered ASCII art should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Terminal text: colored with ANSI escape codes when color is enabled,
+    /// plain otherwise.
+    Term,
+    /// Plain, uncolored text.
+    Text,
+    /// A standalone HTML fragment with one `<span>` per colored character.
+    Html,
+}
+
+/// Render a glyph grid as an HTML fragment: a `<pre>` block containing one
+/// `<span style="color:#rrggbb">` per character, so colored art can be
+/// pasted into a web page.
+pub fn render_html(grid: &[Vec<Glyph>]) -> String {
+    let mut out = String::from("<pre>\n");
+    for row in grid {
+        for glyph in row {
+            let (r, g, b) = glyph.color;
+            out.push_str(&format!(
+                "<span style=\"color:#{:02x}{:02x}{:02x}\">{}</span>",
+                r,
+                g,
+                b,
+                escape_html(glyph.ch)
+            ));
+        }
+        out.push('\n');
+    }
+    out.push_str("</pre>\n");
+    out
+}
+
+/// Escape the handful of characters that are meaningful in HTML text.
+fn escape_html(ch: char) -> String {
+    match ch {
+        '&' => "&amp;".to_string(),
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        ' ' => "&nbsp;".to_string(),
+        other => other.to_string(),
+    }
+}
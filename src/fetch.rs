@@ -0,0 +1,19 @@
+// src/fetch.rs
+
+//! Fetching images from `http(s)://` URLs as an alternative to local file
+//! input. Downloading itself is gated behind the `web_image` cargo
+//! feature so offline builds don't pull in an HTTP client; URL detection
+//! stays available unconditionally so the CLI can report a clear error
+//! when the feature is disabled.
+
+/// Whether `input` looks like an `http(s)://` URL rather than a local path.
+pub fn is_url(input: &str) -> bool {
+    input.starts_with("http://") || input.starts_with("https://")
+}
+
+/// Download the bytes at `url` and decode them as an image.
+#[cfg(feature = "web_image")]
+pub fn fetch_image(url: &str) -> Result<image::DynamicImage, Box<dyn std::error::Error>> {
+    let bytes = reqwest::blocking::get(url)?.bytes()?;
+    Ok(image::load_from_memory(&bytes)?)
+}
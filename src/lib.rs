@@ -0,0 +1,309 @@
+// src/lib.rs
+
+//! Core conversion library for AsciiArtist.
+//!
+//! The CLI binary (`main.rs`) is a thin wrapper around this crate: it parses
+//! arguments into a [`Config`], loads an image, and hands both to
+//! [`render_to_string`] or [`render_ansi`]. Keeping the rendering logic here
+//! (rather than inline in `main`) lets it be exercised from tests or reused
+//! by other programs that want ASCII art without shelling out.
+
+use image::{DynamicImage, GenericImageView};
+
+pub mod anim;
+pub mod charset;
+pub mod edges;
+pub mod export;
+pub mod fetch;
+pub mod term;
+
+/// Strategy used to reduce the source image down to one sample per output
+/// character cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sampling {
+    /// Pick a single source pixel per cell (fast, but aliases on large
+    /// images since most of the source rectangle is simply discarded).
+    Nearest,
+    /// Average every source pixel covered by a cell's rectangle (slower,
+    /// but preserves detail that point sampling would drop).
+    Average,
+}
+
+/// Options controlling how an image is converted to ASCII art.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Output width of the generated ASCII art, in characters.
+    pub width: u32,
+    /// Character ramp used for brightness-to-glyph mapping, ordered from
+    /// darkest to lightest (e.g. `" .:-=+*#%@"`).
+    pub charset: String,
+    /// Character aspect ratio compensation factor applied to the output
+    /// height to correct for non-square terminal characters.
+    pub aspect_ratio_compensation: f32,
+    /// Render direction-aligned structural glyphs (`|`, `-`, `/`, `\`) at
+    /// strong gradients instead of pure brightness shading. See [`edges`].
+    pub edges: bool,
+    /// How each output cell's source pixels are reduced to one sample.
+    pub sampling: Sampling,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            width: 120,
+            charset: String::from(" .:-=+*#%@"),
+            aspect_ratio_compensation: 0.50,
+            edges: false,
+            sampling: Sampling::Average,
+        }
+    }
+}
+
+/// A single sampled output cell: its averaged color and derived brightness.
+struct Cell {
+    r: u8,
+    g: u8,
+    b: u8,
+    brightness: u8,
+}
+
+/// One rendered output character together with the source color it was
+/// derived from, as produced by [`render_glyphs`]. Used by output targets
+/// (e.g. [`crate::export`]) that need per-character color rather than a
+/// single flat string.
+#[derive(Debug, Clone, Copy)]
+pub struct Glyph {
+    pub ch: char,
+    pub color: (u8, u8, u8),
+}
+
+/// Render `img` to plain (uncolored) ASCII art text.
+pub fn render_to_string(img: &DynamicImage, config: &Config) -> String {
+    format_plain(&render_glyphs(img, config))
+}
+
+/// Render `img` to ASCII art with inline ANSI truecolor escape codes.
+pub fn render_ansi(img: &DynamicImage, config: &Config) -> String {
+    format_ansi(&render_glyphs(img, config))
+}
+
+/// Render `img` into a grid of [`Glyph`]s: the character and source color
+/// for every output cell, before any text or ANSI formatting is applied.
+pub fn render_glyphs(img: &DynamicImage, config: &Config) -> Vec<Vec<Glyph>> {
+    let output_width = config.width;
+    let ascii_chars = &config.charset;
+
+    let (original_width, original_height) = img.dimensions();
+    let image_aspect_ratio = original_height as f32 / original_width as f32;
+    let new_height =
+        (output_width as f32 * image_aspect_ratio * config.aspect_ratio_compensation).round() as u32;
+
+    let grid = sample_grid(img, output_width, new_height, config.sampling);
+    let brightness_grid: Vec<Vec<u8>> = grid.iter().map(|row| row.iter().map(|c| c.brightness).collect()).collect();
+
+    grid.iter()
+        .enumerate()
+        .map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(x, cell)| {
+                    let edge_glyph = if config.edges {
+                        edges::classify(&brightness_grid, x, y)
+                    } else {
+                        None
+                    };
+
+                    let ch = match edge_glyph {
+                        Some(glyph) => glyph,
+                        None => {
+                            let char_index = (cell.brightness as f32 / 255.0 * (ascii_chars.chars().count() - 1) as f32)
+                                .round() as usize;
+                            ascii_chars.chars().nth(char_index).unwrap_or(' ')
+                        }
+                    };
+
+                    Glyph { ch, color: (cell.r, cell.g, cell.b) }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Flatten a glyph grid into plain text, one row per line.
+fn format_plain(grid: &[Vec<Glyph>]) -> String {
+    let mut out = String::new();
+    for row in grid {
+        for glyph in row {
+            out.push(glyph.ch);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Flatten a glyph grid into text with inline ANSI truecolor escape codes.
+///
+/// Tracks the currently active foreground color so a new
+/// `\x1b[38;2;r;g;bm` sequence is only emitted when it actually changes,
+/// rather than one set+reset pair per cell, resetting once at the end of
+/// each line.
+fn format_ansi(grid: &[Vec<Glyph>]) -> String {
+    let mut out = String::new();
+    for row in grid {
+        let mut active_color: Option<(u8, u8, u8)> = None;
+        for glyph in row {
+            if active_color != Some(glyph.color) {
+                let (r, g, b) = glyph.color;
+                out.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+                active_color = Some(glyph.color);
+            }
+            out.push(glyph.ch);
+        }
+        if active_color.is_some() {
+            out.push_str("\x1b[0m");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Sample `img` down to an `output_width` x `new_height` grid of cells,
+/// one per output character, using the given [`Sampling`] strategy.
+fn sample_grid(img: &DynamicImage, output_width: u32, new_height: u32, sampling: Sampling) -> Vec<Vec<Cell>> {
+    let (original_width, original_height) = img.dimensions();
+
+    (0..new_height)
+        .map(|y| {
+            (0..output_width)
+                .map(|x| match sampling {
+                    Sampling::Nearest => {
+                        let original_x_coord = (x as f32 / output_width as f32 * original_width as f32) as u32;
+                        let original_y_coord = (y as f32 / new_height as f32 * original_height as f32) as u32;
+                        let pixel = img.get_pixel(original_x_coord, original_y_coord);
+                        cell_from_rgb(pixel[0], pixel[1], pixel[2])
+                    }
+                    Sampling::Average => {
+                        average_cell(img, x, y, output_width, new_height, original_width, original_height)
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Build a [`Cell`] from an RGB triple, deriving its perceptual brightness.
+fn cell_from_rgb(r: u8, g: u8, b: u8) -> Cell {
+    let brightness = (0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32) as u8;
+    Cell { r, g, b, brightness }
+}
+
+/// Average every source pixel covered by output cell `(x, y)`'s rectangle.
+fn average_cell(
+    img: &DynamicImage,
+    x: u32,
+    y: u32,
+    output_width: u32,
+    new_height: u32,
+    original_width: u32,
+    original_height: u32,
+) -> Cell {
+    let x0 = (x as f32 / output_width as f32 * original_width as f32) as u32;
+    let x1 = (((x + 1) as f32 / output_width as f32 * original_width as f32).ceil() as u32)
+        .max(x0 + 1)
+        .min(original_width);
+    let y0 = (y as f32 / new_height as f32 * original_height as f32) as u32;
+    let y1 = (((y + 1) as f32 / new_height as f32 * original_height as f32).ceil() as u32)
+        .max(y0 + 1)
+        .min(original_height);
+
+    let (mut r_sum, mut g_sum, mut b_sum, mut count) = (0u64, 0u64, 0u64, 0u64);
+    for sy in y0..y1 {
+        for sx in x0..x1 {
+            let pixel = img.get_pixel(sx, sy);
+            r_sum += pixel[0] as u64;
+            g_sum += pixel[1] as u64;
+            b_sum += pixel[2] as u64;
+            count += 1;
+        }
+    }
+
+    let r = (r_sum / count) as u8;
+    let g = (g_sum / count) as u8;
+    let b = (b_sum / count) as u8;
+    cell_from_rgb(r, g, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 4x2 image split into a black left half and a white right half.
+    fn split_image() -> DynamicImage {
+        let buffer = image::RgbImage::from_fn(4, 2, |x, _y| {
+            if x < 2 {
+                image::Rgb([0, 0, 0])
+            } else {
+                image::Rgb([255, 255, 255])
+            }
+        });
+        DynamicImage::ImageRgb8(buffer)
+    }
+
+    #[test]
+    fn average_sampling_blends_each_cells_source_rectangle() {
+        let img = split_image();
+        let grid = sample_grid(&img, 2, 1, Sampling::Average);
+        assert_eq!(grid[0][0].brightness, 0);
+        assert_eq!(grid[0][1].brightness, 255);
+    }
+
+    #[test]
+    fn nearest_sampling_picks_a_single_source_pixel() {
+        let img = split_image();
+        let grid = sample_grid(&img, 4, 2, Sampling::Nearest);
+        assert_eq!((grid[0][0].r, grid[0][0].g, grid[0][0].b), (0, 0, 0));
+        assert_eq!((grid[0][3].r, grid[0][3].g, grid[0][3].b), (255, 255, 255));
+    }
+
+    #[test]
+    fn render_glyphs_maps_dark_and_bright_cells_to_opposite_ends_of_the_ramp() {
+        let img = split_image();
+        let config = Config { width: 2, sampling: Sampling::Average, ..Config::default() };
+        let grid = render_glyphs(&img, &config);
+        assert_eq!(grid[0][0].ch, config.charset.chars().next().unwrap());
+        assert_eq!(grid[0][1].ch, config.charset.chars().last().unwrap());
+    }
+
+    #[test]
+    fn render_to_string_has_one_line_per_row_and_no_escape_codes() {
+        let img = split_image();
+        let config = Config { width: 2, sampling: Sampling::Average, ..Config::default() };
+        let text = render_to_string(&img, &config);
+        assert_eq!(text.lines().count(), 1);
+        assert!(!text.contains('\x1b'));
+    }
+
+    #[test]
+    fn render_ansi_colors_each_cell_with_its_source_rgb() {
+        let img = split_image();
+        let config = Config { width: 2, sampling: Sampling::Average, ..Config::default() };
+        let art = render_ansi(&img, &config);
+        assert!(art.contains("\x1b[38;2;0;0;0m"));
+        assert!(art.contains("\x1b[38;2;255;255;255m"));
+        assert!(art.ends_with("\x1b[0m\n"));
+    }
+
+    #[test]
+    fn format_ansi_emits_one_escape_sequence_per_run_of_same_colored_glyphs() {
+        let row = vec![
+            Glyph { ch: 'a', color: (10, 20, 30) },
+            Glyph { ch: 'b', color: (10, 20, 30) },
+            Glyph { ch: 'c', color: (40, 50, 60) },
+        ];
+        let out = format_ansi(&[row]);
+        assert_eq!(out.matches("\x1b[38;2;10;20;30m").count(), 1);
+        assert_eq!(out.matches("\x1b[38;2;40;50;60m").count(), 1);
+        assert_eq!(out, "\x1b[38;2;10;20;30mab\x1b[38;2;40;50;60mc\x1b[0m\n");
+    }
+}
+
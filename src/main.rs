@@ -1,11 +1,10 @@
 // src/main.rs
 
 // Import necessary crates and modules
-use image::{GenericImageView}; // For image processing
+use ascii_artist::{anim, charset, export, fetch, render_ansi, render_glyphs, render_to_string, term, Config, Sampling}; // Library rendering API
+use image::GenericImageView; // For img.width()/img.height()
 use std::path::PathBuf; // For handling file paths
 use clap::Parser; // For command-line argument parsing
-use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor}; // For terminal color output
-use std::io::Write; // For writing to StandardStream (used by termcolor)
 use std::time::Instant; // For timing the execution
 
 /// A simple Rust ASCII art generator that converts images into colored or black-and-white ASCII art.
@@ -82,19 +81,28 @@ USAGE EXAMPLES:
 "
 )]
 struct Args {
-    /// Path to the input image file (e.g., .jpg, .png).
+    /// Path to the input image file (e.g., .jpg, .png), or an http(s)://
+    /// URL to download and convert (requires the `web_image` feature).
     /// This argument is required. If not provided, clap will show an error.
     #[arg(short, long, required = true)]
-    input: Option<PathBuf>, // Still Option<PathBuf> even with required=true
+    input: Option<String>, // Still Option<String> even with required=true
 
     /// The desired width of the ASCII art output (in characters).
-    /// Defaults to 120 characters if not specified.
-    #[arg(short, long, default_value_t = 120)]
-    width: u32,
+    /// Ignored when the output is auto-fit to the terminal (--fit, or no
+    /// --width); if the terminal size can't be determined, this width is
+    /// used instead, or 120 when it isn't given.
+    #[arg(short, long)]
+    width: Option<u32>,
+
+    /// Auto-fit the output to the current terminal's size, preserving
+    /// aspect ratio. This is the default when --width isn't given.
+    #[arg(long)]
+    fit: bool,
 
     /// The character set to use for ASCII conversion, ordered from darkest to lightest.
-    /// Example: " .:-=+*#%@" (dark to light) or "@#$%*+=-. " (light to dark)
-    #[arg(short, long, default_value_t = String::from(" .:-=+*#%@"))]
+    /// Accepts a raw ramp (e.g. " .:-=+*#%@") or one of the built-in names:
+    /// default, block, blocks, braille, detailed, russian.
+    #[arg(short, long, default_value_t = String::from("default"))]
     charset: String,
 
     /// Enable colored output in the terminal. If not set, output will be black and white.
@@ -107,6 +115,78 @@ struct Args {
     /// increase it (e.g., 0.65) if it appears too stretched vertically.
     #[arg(short = 'A', long, default_value_t = 0.50)]
     aspect_ratio_compensation: f32,
+
+    /// Reverse the effective character ramp, so the tool reads correctly
+    /// on light terminal backgrounds without manually reversing --charset.
+    #[arg(short = 'I', long)]
+    invert: bool,
+
+    /// Repeat animated GIF playback indefinitely instead of playing once.
+    /// Has no effect on still images.
+    #[arg(short = 'l', long)]
+    r#loop: bool,
+
+    /// Override each GIF frame's own delay with a fixed frames-per-second
+    /// rate during animated playback. Has no effect on still images.
+    #[arg(long)]
+    fps: Option<f32>,
+
+    /// Render direction-aligned structural glyphs (|, -, /, \) at strong
+    /// gradients instead of pure brightness shading, for a line-art look.
+    #[arg(long)]
+    edges: bool,
+
+    /// How each output cell's source pixels are reduced to one sample.
+    /// `average` (the default) box-averages every source pixel a cell
+    /// covers, preserving more detail on large images; `nearest` picks a
+    /// single source pixel per cell and is faster but aliases more.
+    #[arg(long, value_enum, default_value_t = SamplingArg::Average)]
+    sampling: SamplingArg,
+
+    /// Write the rendered art to this file instead of the terminal.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Output target: `term` (colored/plain terminal text), `text` (plain
+    /// text file), or `html` (a standalone colored `<pre>` fragment).
+    #[arg(long, value_enum, default_value_t = FormatArg::Term)]
+    format: FormatArg,
+}
+
+/// CLI-facing mirror of [`export::Format`] so it can derive `ValueEnum`
+/// without pulling a `clap` dependency into the library crate.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum FormatArg {
+    Term,
+    Text,
+    Html,
+}
+
+impl From<FormatArg> for export::Format {
+    fn from(arg: FormatArg) -> Self {
+        match arg {
+            FormatArg::Term => export::Format::Term,
+            FormatArg::Text => export::Format::Text,
+            FormatArg::Html => export::Format::Html,
+        }
+    }
+}
+
+/// CLI-facing mirror of [`ascii_artist::Sampling`] so it can derive `ValueEnum`
+/// without pulling a `clap` dependency into the library crate.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum SamplingArg {
+    Nearest,
+    Average,
+}
+
+impl From<SamplingArg> for Sampling {
+    fn from(arg: SamplingArg) -> Self {
+        match arg {
+            SamplingArg::Nearest => Sampling::Nearest,
+            SamplingArg::Average => Sampling::Average,
+        }
+    }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -116,58 +196,110 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 1. Parse command-line arguments. clap will handle required argument checks automatically.
     let args = Args::parse();
 
-    // Now, image_path is guaranteed to be a Some(PathBuf) because `required = true`
-    // We need to unwrap it to get the PathBuf directly.
-    let image_path = args.input.expect("Input image path is required but was not provided. This should be handled by clap.");
-    let output_width = args.width;
-    let ascii_chars = args.charset;
-    let enable_color = args.color;
-    let aspect_ratio_compensation = args.aspect_ratio_compensation;
+    // Now, input is guaranteed to be a Some(String) because `required = true`
+    // We need to unwrap it to get the value directly.
+    let input = args.input.expect("Input image path is required but was not provided. This should be handled by clap.");
 
-    // 3. Initialize the standard output stream for color or non-color output
-    let mut stdout = StandardStream::stdout(if enable_color { ColorChoice::Auto } else { ColorChoice::Never });
+    // 2. Resolve the input to an in-memory still image, or to a fully
+    //    decoded animated GIF (decoded exactly once, then inspected and
+    //    played from the same `Animation`).
+    enum Source {
+        Still(image::DynamicImage),
+        Gif(anim::Animation),
+    }
 
-    // 4. Load the image from the specified path
-    writeln!(&mut stdout, "Loading image from: {}...", image_path.display())?;
-    let img = image::open(&image_path)?;
-    writeln!(&mut stdout, "Image dimensions: {}x{}", img.width(), img.height())?;
+    let source = if fetch::is_url(&input) {
+        #[cfg(feature = "web_image")]
+        {
+            println!("Downloading image from: {}...", input);
+            Source::Still(fetch::fetch_image(&input)?)
+        }
+        #[cfg(not(feature = "web_image"))]
+        {
+            return Err(format!(
+                "'{}' looks like a URL, but this build was compiled without the `web_image` feature",
+                input
+            )
+            .into());
+        }
+    } else {
+        let image_path = PathBuf::from(&input);
 
-    // 5. Determine the scaling factor to maintain aspect ratio while fitting the target width
-    let (original_width, original_height) = img.dimensions();
-    let image_aspect_ratio = original_height as f32 / original_width as f32;
-    let new_height = (output_width as f32 * image_aspect_ratio * aspect_ratio_compensation).round() as u32;
+        if anim::is_gif(&image_path) {
+            let animation = anim::Animation::load(&image_path)?;
+            if animation.is_animated() {
+                Source::Gif(animation)
+            } else {
+                Source::Still(animation.into_first_frame())
+            }
+        } else {
+            println!("Loading image from: {}...", image_path.display());
+            Source::Still(image::open(&image_path)?)
+        }
+    };
 
-    // 6. Process pixels and generate ASCII art
-    for y in 0..new_height {
-        for x in 0..output_width {
-            let original_x_coord = (x as f32 / output_width as f32 * original_width as f32) as u32;
-            let original_y_coord = (y as f32 / new_height as f32 * original_height as f32) as u32;
+    // 3. Auto-fit the width to the terminal when asked, or when no
+    //    explicit --width was given, preserving aspect ratio.
+    let (source_width, source_height) = match &source {
+        Source::Still(img) => img.dimensions(),
+        Source::Gif(animation) => animation.dimensions(),
+    };
+    let image_aspect_ratio = source_height as f32 / source_width as f32;
 
-            let pixel = img.get_pixel(original_x_coord, original_y_coord);
+    let width = if args.fit || args.width.is_none() {
+        match term::dimensions() {
+            Some((cols, rows)) => term::fit_width(cols, rows, image_aspect_ratio, args.aspect_ratio_compensation),
+            None => args.width.unwrap_or(120),
+        }
+    } else {
+        args.width.unwrap_or(120)
+    };
 
-            let r = pixel[0];
-            let g = pixel[1];
-            let b = pixel[2];
+    let config = Config {
+        width,
+        charset: charset::resolve(&args.charset, args.invert)?,
+        aspect_ratio_compensation: args.aspect_ratio_compensation,
+        edges: args.edges,
+        sampling: args.sampling.into(),
+    };
 
-            let brightness = (0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32) as u8;
+    let img = match source {
+        Source::Gif(animation) => {
+            if args.output.is_some() || !matches!(args.format, FormatArg::Term) {
+                return Err(
+                    "--output/--format aren't supported for animated GIF input, which always plays to the terminal"
+                        .into(),
+                );
+            }
+            animation.play(&config, args.color, args.r#loop, args.fps);
+            return Ok(());
+        }
+        Source::Still(img) => img,
+    };
+    println!("Image dimensions: {}x{}", img.width(), img.height());
 
-            let char_index = (brightness as f32 / 255.0 * (ascii_chars.len() - 1) as f32).round() as usize;
-            let ascii_char = ascii_chars.chars().nth(char_index).unwrap_or(' ');
+    // 4. Render to the selected output target and write it to the file or
+    //    terminal, branching on the target instead of always writing to
+    //    StandardStream.
+    let format: export::Format = args.format.into();
+    let art = match format {
+        export::Format::Term if args.color => render_ansi(&img, &config),
+        export::Format::Term => render_to_string(&img, &config),
+        export::Format::Text => render_to_string(&img, &config),
+        export::Format::Html => export::render_html(&render_glyphs(&img, &config)),
+    };
 
-            if enable_color {
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Rgb(r, g, b))))?;
-                write!(&mut stdout, "{}", ascii_char)?;
-                stdout.reset()?;
-            } else {
-                write!(&mut stdout, "{}", ascii_char)?;
-            }
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, &art)?;
+            println!("Wrote art to {}", path.display());
         }
-        writeln!(&mut stdout)?;
+        None => print!("{}", art),
     }
 
     // End timing and print duration
     let duration = start_time.elapsed();
-    writeln!(&mut stdout, "\nConversion complete! Time taken: {:.2?}", duration)?;
+    println!("\nConversion complete! Time taken: {:.2?}", duration);
 
     Ok(())
-}
\ No newline at end of file
+}
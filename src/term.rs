@@ -0,0 +1,56 @@
+// src/term.rs
+
+//! Auto-fitting the output to the current terminal window (`--fit`).
+//!
+//! Queries the terminal's current `(columns, rows)` and computes the
+//! widest output width that fits under both constraints at once, honoring
+//! the same aspect ratio compensation applied to manually-sized output.
+
+use terminal_size::{terminal_size, Height, Width};
+
+/// Lines reserved below the art for status text (e.g. the "Conversion
+/// complete" line), so the whole image stays visible without scrolling.
+const STATUS_LINES: u32 = 2;
+
+/// The current terminal's `(columns, rows)`, or `None` if it can't be
+/// determined (e.g. output is redirected to a file).
+pub fn dimensions() -> Option<(u32, u32)> {
+    let (Width(cols), Height(rows)) = terminal_size()?;
+    Some((cols as u32, rows as u32))
+}
+
+/// Compute the widest output that fits within `cols` columns and `rows`
+/// rows (minus a couple of status lines), while preserving
+/// `image_aspect_ratio` under the existing `aspect_ratio_compensation`.
+pub fn fit_width(cols: u32, rows: u32, image_aspect_ratio: f32, aspect_ratio_compensation: f32) -> u32 {
+    let max_height = rows.saturating_sub(STATUS_LINES).max(1);
+
+    let width_from_height = if image_aspect_ratio > 0.0 && aspect_ratio_compensation > 0.0 {
+        (max_height as f32 / (image_aspect_ratio * aspect_ratio_compensation)).floor() as u32
+    } else {
+        cols
+    };
+
+    cols.min(width_from_height).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamps_to_rows_when_height_is_the_tighter_constraint() {
+        // max_height = 50 - 2 = 48; width_from_height = 48 / (1.0 * 0.5) = 96.
+        assert_eq!(fit_width(200, 50, 1.0, 0.5), 96);
+    }
+
+    #[test]
+    fn clamps_to_cols_when_width_is_the_tighter_constraint() {
+        assert_eq!(fit_width(40, 1000, 1.0, 0.5), 40);
+    }
+
+    #[test]
+    fn falls_back_to_cols_for_a_degenerate_aspect_ratio() {
+        assert_eq!(fit_width(120, 40, 0.0, 0.5), 120);
+    }
+}